@@ -1,17 +1,63 @@
-use std::{collections::BTreeSet, str::FromStr};
+use std::{cell::RefCell, collections::BTreeSet, rc::Rc, str::FromStr};
 
 use crate::reader::{v1, v2, Document};
 
 use super::v2_to_v3::CompatV2ToV3;
 use crate::Result;
 
+/// A single piece of data that could not be carried over as-is while converting a v1 dump to v2.
+#[derive(Debug, Clone)]
+pub enum MigrationWarning {
+    /// An enqueued v1 task was dropped because its update file could not be recovered.
+    SkippedEnqueuedTask { index_uid: String, task_id: u64 },
+    /// A task using the `Customs` update type was dropped, as it is no longer supported in v2.
+    UnsupportedCustomsTask { index_uid: String, task_id: u64 },
+    /// The `WordsPosition` ranking rule was removed, as it no longer exists in v2.
+    RemovedRankingRule { index_uid: String, task_id: Option<u64> },
+}
+
+/// Accumulates the [`MigrationWarning`]s emitted while converting a v1 dump to v2, so that
+/// callers can find out exactly what data was lost instead of scraping logs.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationReport {
+    warnings: Vec<MigrationWarning>,
+}
+
+impl MigrationReport {
+    fn push(&mut self, warning: MigrationWarning) {
+        self.warnings.push(warning);
+    }
+
+    pub fn warnings(&self) -> &[MigrationWarning] {
+        &self.warnings
+    }
+}
+
+/// A pre-flight summary of what a real v1 to v2 migration would do to a single index, produced
+/// by [`CompatIndexV1ToV2::validate`] without materializing any document or update file.
+#[derive(Debug, Clone, Default)]
+pub struct IndexMigrationSummary {
+    pub index_uid: String,
+    pub convertible_documents: u64,
+    pub dropped_tasks: u64,
+    pub rewritten_ranking_rules: u64,
+}
+
+/// A pre-flight summary of what a real v1 to v2 migration would do, produced by
+/// [`CompatV1ToV2::validate`] without materializing any document or update file.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationSummary {
+    pub indexes: Vec<IndexMigrationSummary>,
+}
+
 pub struct CompatV1ToV2 {
     pub from: v1::V1Reader,
+    report: Rc<RefCell<MigrationReport>>,
 }
 
 impl CompatV1ToV2 {
     pub fn new(v1: v1::V1Reader) -> Self {
-        Self { from: v1 }
+        Self { from: v1, report: Rc::new(RefCell::new(MigrationReport::default())) }
     }
 
     pub fn to_v3(self) -> CompatV2ToV3 {
@@ -26,6 +72,13 @@ impl CompatV1ToV2 {
         self.from.date()
     }
 
+    /// Returns the warnings accumulated so far while converting this dump, e.g. the tasks and
+    /// ranking rules that were dropped or rewritten during a call to [`Self::tasks`] or
+    /// [`CompatIndexV1ToV2::settings`].
+    pub fn report(&self) -> std::cell::Ref<'_, MigrationReport> {
+        self.report.borrow()
+    }
+
     pub fn index_uuid(&self) -> Vec<v2::meta::IndexUuid> {
         self.from
             .index_uuid()
@@ -40,12 +93,32 @@ impl CompatV1ToV2 {
     }
 
     pub fn indexes(&self) -> Result<impl Iterator<Item = Result<CompatIndexV1ToV2>> + '_> {
-        Ok(self.from.indexes()?.map(|index_reader| Ok(CompatIndexV1ToV2 { from: index_reader? })))
+        Ok(self.from.indexes()?.map(|index_reader| {
+            Ok(CompatIndexV1ToV2 { from: index_reader?, report: self.report.clone() })
+        }))
+    }
+
+    /// Previews the conversion of this dump to v2 without writing anything, by running every
+    /// index through [`CompatIndexV1ToV2::validate`]. This reuses the same `convert_*` helpers
+    /// as [`Self::tasks`], so the preview cannot drift from what a real import would do. The
+    /// warnings produced by this preview are not recorded in [`Self::report`]: calling
+    /// `validate()` before actually migrating with [`Self::tasks`]/[`CompatIndexV1ToV2::settings`]
+    /// on this same reader will not duplicate any entry in the final report.
+    pub fn validate(&self) -> Result<MigrationSummary> {
+        let indexes = self
+            .indexes()?
+            .map(|index| {
+                let mut index = index?;
+                index.validate()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(MigrationSummary { indexes })
     }
 
     pub fn tasks(
         &mut self,
     ) -> Box<dyn Iterator<Item = Result<(v2::Task, Option<v2::UpdateFile>)>> + '_> {
+        let report = self.report.clone();
         // Convert an error here to an iterator yielding the error
         let indexes = match self.from.indexes() {
             Ok(indexes) => indexes,
@@ -57,22 +130,27 @@ impl CompatV1ToV2 {
                     Ok(index_reader) => index_reader,
                     Err(err) => return Box::new(std::iter::once(Err(err))),
                 };
+                let index_uid = index_reader.metadata().uid.clone();
+                let report = report.clone();
                 Box::new(
                     index_reader
                         .tasks()
                         // Filter out the UpdateStatus::Customs variant that is not supported in v2
-                        // and enqueued tasks, that don't contain the necessary update file in v1
+                        // and enqueued tasks that don't contain the necessary update file in v1.
+                        // Both are recorded in the migration report rather than silently dropped.
                         .filter_map(move |task| -> Option<_> {
                             let task = match task {
                                 Ok(task) => task,
                                 Err(err) => return Some(Err(err)),
                             };
+                            let (update, update_file) = convert_update_status(
+                                task,
+                                &index_uid,
+                                &mut report.borrow_mut(),
+                            )?;
                             Some(Ok((
-                                v2::Task {
-                                    uuid: uuid::Uuid::from_u128(index as u128),
-                                    update: Option::from(task)?,
-                                },
-                                None,
+                                v2::Task { uuid: uuid::Uuid::from_u128(index as u128), update },
+                                update_file,
                             )))
                         }),
                 )
@@ -84,6 +162,7 @@ impl CompatV1ToV2 {
 
 pub struct CompatIndexV1ToV2 {
     pub from: v1::V1IndexReader,
+    report: Rc<RefCell<MigrationReport>>,
 }
 
 impl CompatIndexV1ToV2 {
@@ -96,68 +175,116 @@ impl CompatIndexV1ToV2 {
     }
 
     pub fn settings(&mut self) -> Result<v2::settings::Settings<v2::settings::Checked>> {
-        Ok(v2::settings::Settings::<v2::settings::Unchecked>::from(self.from.settings()?).check())
+        let index_uid = self.metadata().uid.clone();
+        let settings = self.from.settings()?;
+        let settings = convert_settings(settings, &index_uid, &mut self.report.borrow_mut());
+        Ok(settings.check())
     }
-}
 
-impl From<v1::settings::Settings> for v2::Settings<v2::Unchecked> {
-    fn from(source: v1::settings::Settings) -> Self {
-        let displayed_attributes = source
-            .displayed_attributes
-            .map(|opt| opt.map(|displayed_attributes| displayed_attributes.into_iter().collect()));
-        let attributes_for_faceting = source.attributes_for_faceting.map(|opt| {
-            opt.map(|attributes_for_faceting| attributes_for_faceting.into_iter().collect())
-        });
-        let ranking_rules = source.ranking_rules.map(|opt| {
-            opt.map(|ranking_rules| {
-                ranking_rules
-                    .into_iter()
-                    .filter_map(|ranking_rule| {
-                        match v1::settings::RankingRule::from_str(&ranking_rule) {
-                            Ok(ranking_rule) => {
-                                let criterion: Option<v2::settings::Criterion> =
-                                    ranking_rule.into();
-                                criterion.as_ref().map(ToString::to_string)
-                            }
-                            Err(()) => Some(ranking_rule),
-                        }
-                    })
-                    .collect()
-            })
-        });
-
-        Self {
-            displayed_attributes,
-            searchable_attributes: source.searchable_attributes,
-            filterable_attributes: attributes_for_faceting,
-            ranking_rules,
-            stop_words: source.stop_words,
-            synonyms: source.synonyms,
-            distinct_attribute: source.distinct_attribute,
-            _kind: std::marker::PhantomData,
+    /// Walks this index's documents, tasks and settings applying the same conversions as
+    /// [`Self::documents`], [`CompatV1ToV2::tasks`] and [`Self::settings`], but discards the
+    /// produced values instead of materializing them.
+    ///
+    /// This dry run must not affect the outcome of a later real import on the same reader, so it
+    /// accumulates warnings into its own disposable [`MigrationReport`] instead of the one shared
+    /// by [`Self::documents`]/[`CompatV1ToV2::tasks`]/[`Self::settings`]; nothing pushed here ever
+    /// reaches [`CompatV1ToV2::report`]. The only settings-level data loss this module currently
+    /// tracks is the removed `WordsPosition` ranking rule, already counted by
+    /// `rewritten_ranking_rules`: there is no other settings field that fails to convert, so the
+    /// summary has no separate "unrepresentable fields" list.
+    pub fn validate(&mut self) -> Result<IndexMigrationSummary> {
+        let index_uid = self.metadata().uid.clone();
+        let mut report = MigrationReport::default();
+
+        let convertible_documents =
+            self.documents()?.try_fold(0u64, |count, document| document.map(|_| count + 1))?;
+
+        let settings = self.from.settings()?;
+        convert_settings(settings, &index_uid, &mut report);
+
+        let mut dropped_tasks = 0u64;
+        for task in self.from.tasks() {
+            let task = task?;
+            if convert_update_status(task, &index_uid, &mut report).is_none() {
+                dropped_tasks += 1;
+            }
         }
+
+        let rewritten_ranking_rules = report
+            .warnings()
+            .iter()
+            .filter(|warning| matches!(warning, MigrationWarning::RemovedRankingRule { .. }))
+            .count() as u64;
+
+        Ok(IndexMigrationSummary {
+            index_uid,
+            convertible_documents,
+            dropped_tasks,
+            rewritten_ranking_rules,
+        })
     }
 }
 
-impl From<v1::update::UpdateStatus> for Option<v2::updates::UpdateStatus> {
-    fn from(source: v1::update::UpdateStatus) -> Self {
-        use v1::update::UpdateStatus as UpdateStatusV1;
-        use v2::updates::UpdateStatus as UpdateStatusV2;
-        Some(match source {
-            UpdateStatusV1::Enqueued { content } => {
-                log::warn!(
-                    "Cannot import task {} (importing enqueued tasks from v1 dumps is unsupported)",
-                    content.update_id
-                );
-                log::warn!("Task will be skipped in the queue of imported tasks.");
+fn convert_update_status(
+    source: v1::update::UpdateStatus,
+    index_uid: &str,
+    report: &mut MigrationReport,
+) -> Option<(v2::updates::UpdateStatus, Option<v2::UpdateFile>)> {
+    use v1::update::UpdateStatus as UpdateStatusV1;
+    use v2::updates::UpdateStatus as UpdateStatusV2;
+    Some(match source {
+        UpdateStatusV1::Enqueued { content } => {
+            let update_id = content.update_id;
+            let enqueued_at = content.enqueued_at;
+            // v1 dumps don't keep a queue of pending updates: the only trace of an enqueued
+            // task's payload is the update file it was writing to on disk, which may or may not
+            // still be reachable from the dump layout.
+            let update_file = content.content.as_deref().and_then(|path| {
+                v2::UpdateFile::new(path)
+                    .map_err(|err| {
+                        // Reached only when content.content was Some(path): a real file
+                        // reference that failed to open/read, not ordinary data loss from a v1
+                        // dump that never had the file, so it is also logged; the dropped task
+                        // itself is still recorded in the report below.
+                        log::warn!(
+                            "Failed to recover update file for enqueued task {update_id} at {}: {err}",
+                            path.display()
+                        );
+                    })
+                    .ok()
+            });
+            let meta = convert_update_type(content.update_type, index_uid, update_id, report)?;
+
+            let Some(update_file) = update_file else {
+                report.push(MigrationWarning::SkippedEnqueuedTask {
+                    index_uid: index_uid.to_string(),
+                    task_id: update_id,
+                });
 
                 return None;
-            }
-            UpdateStatusV1::Failed { content } => UpdateStatusV2::Failed(v2::updates::Failed {
+            };
+
+            return Some((
+                UpdateStatusV2::Enqueued(v2::updates::Enqueued {
+                    update_id,
+                    meta,
+                    enqueued_at,
+                    content: None,
+                }),
+                Some(update_file),
+            ));
+        }
+        UpdateStatusV1::Failed { content } => (
+            UpdateStatusV2::Failed(v2::updates::Failed {
                 from: v2::updates::Processing {
                     from: v2::updates::Enqueued {
                         update_id: content.update_id,
-                        meta: Option::from(content.update_type)?,
+                        meta: convert_update_type(
+                            content.update_type,
+                            index_uid,
+                            content.update_id,
+                            report,
+                        )?,
                         enqueued_at: content.enqueued_at,
                         content: None,
                     },
@@ -178,138 +305,202 @@ impl From<v1::update::UpdateStatus> for Option<v2::updates::UpdateStatus> {
                 },
                 failed_at: content.processed_at,
             }),
-            UpdateStatusV1::Processed { content } => {
-                UpdateStatusV2::Processed(v2::updates::Processed {
-                    success: match &content.update_type {
-                        v1::update::UpdateType::ClearAll => {
-                            v2::updates::UpdateResult::DocumentDeletion { deleted: u64::MAX }
-                        }
-                        v1::update::UpdateType::Customs => v2::updates::UpdateResult::Other,
-                        v1::update::UpdateType::DocumentsAddition { number } => {
-                            v2::updates::UpdateResult::DocumentsAddition(
-                                v2::updates::DocumentAdditionResult { nb_documents: *number },
-                            )
-                        }
-                        v1::update::UpdateType::DocumentsPartial { number } => {
-                            v2::updates::UpdateResult::DocumentsAddition(
-                                v2::updates::DocumentAdditionResult { nb_documents: *number },
-                            )
-                        }
-                        v1::update::UpdateType::DocumentsDeletion { number } => {
-                            v2::updates::UpdateResult::DocumentDeletion { deleted: *number as u64 }
-                        }
-                        v1::update::UpdateType::Settings { .. } => v2::updates::UpdateResult::Other,
-                    },
-                    processed_at: content.processed_at,
-                    from: v2::updates::Processing {
-                        from: v2::updates::Enqueued {
-                            update_id: content.update_id,
-                            meta: Option::from(content.update_type)?,
-                            enqueued_at: content.enqueued_at,
-                            content: None,
-                        },
-                        started_processing_at: content.processed_at
-                            - std::time::Duration::from_secs_f64(content.duration),
+            None,
+        ),
+        UpdateStatusV1::Processed { content } => (
+            UpdateStatusV2::Processed(v2::updates::Processed {
+                success: match &content.update_type {
+                    v1::update::UpdateType::ClearAll => {
+                        v2::updates::UpdateResult::DocumentDeletion { deleted: u64::MAX }
+                    }
+                    v1::update::UpdateType::Customs => v2::updates::UpdateResult::Other,
+                    v1::update::UpdateType::DocumentsAddition { number } => {
+                        v2::updates::UpdateResult::DocumentsAddition(
+                            v2::updates::DocumentAdditionResult { nb_documents: *number },
+                        )
+                    }
+                    v1::update::UpdateType::DocumentsPartial { number } => {
+                        v2::updates::UpdateResult::DocumentsAddition(
+                            v2::updates::DocumentAdditionResult { nb_documents: *number },
+                        )
+                    }
+                    v1::update::UpdateType::DocumentsDeletion { number } => {
+                        v2::updates::UpdateResult::DocumentDeletion { deleted: *number as u64 }
+                    }
+                    v1::update::UpdateType::Settings { .. } => v2::updates::UpdateResult::Other,
+                },
+                processed_at: content.processed_at,
+                from: v2::updates::Processing {
+                    from: v2::updates::Enqueued {
+                        update_id: content.update_id,
+                        meta: convert_update_type(
+                            content.update_type,
+                            index_uid,
+                            content.update_id,
+                            report,
+                        )?,
+                        enqueued_at: content.enqueued_at,
+                        content: None,
                     },
-                })
-            }
-        })
-    }
+                    started_processing_at: content.processed_at
+                        - std::time::Duration::from_secs_f64(content.duration),
+                },
+            }),
+            None,
+        ),
+    })
 }
 
-impl From<v1::update::UpdateType> for Option<v2::updates::UpdateMeta> {
-    fn from(source: v1::update::UpdateType) -> Self {
-        Some(match source {
-            v1::update::UpdateType::ClearAll => v2::updates::UpdateMeta::ClearDocuments,
-            v1::update::UpdateType::Customs => {
-                log::warn!("Ignoring task with type 'Customs' that is no longer supported");
-                return None;
-            }
-            v1::update::UpdateType::DocumentsAddition { .. } => {
-                v2::updates::UpdateMeta::DocumentsAddition {
-                    method: v2::updates::IndexDocumentsMethod::ReplaceDocuments,
-                    format: v2::updates::UpdateFormat::Json,
-                    primary_key: None,
-                }
-            }
-            v1::update::UpdateType::DocumentsPartial { .. } => {
-                v2::updates::UpdateMeta::DocumentsAddition {
-                    method: v2::updates::IndexDocumentsMethod::UpdateDocuments,
-                    format: v2::updates::UpdateFormat::Json,
-                    primary_key: None,
-                }
-            }
-            v1::update::UpdateType::DocumentsDeletion { .. } => {
-                v2::updates::UpdateMeta::DeleteDocuments { ids: vec![] }
+fn convert_update_type(
+    source: v1::update::UpdateType,
+    index_uid: &str,
+    task_id: u64,
+    report: &mut MigrationReport,
+) -> Option<v2::updates::UpdateMeta> {
+    Some(match source {
+        v1::update::UpdateType::ClearAll => v2::updates::UpdateMeta::ClearDocuments,
+        v1::update::UpdateType::Customs => {
+            report.push(MigrationWarning::UnsupportedCustomsTask {
+                index_uid: index_uid.to_string(),
+                task_id,
+            });
+
+            return None;
+        }
+        v1::update::UpdateType::DocumentsAddition { .. } => {
+            v2::updates::UpdateMeta::DocumentsAddition {
+                method: v2::updates::IndexDocumentsMethod::ReplaceDocuments,
+                format: v2::updates::UpdateFormat::Json,
+                primary_key: None,
             }
-            v1::update::UpdateType::Settings { settings } => {
-                v2::updates::UpdateMeta::Settings((*settings).into())
+        }
+        v1::update::UpdateType::DocumentsPartial { .. } => {
+            v2::updates::UpdateMeta::DocumentsAddition {
+                method: v2::updates::IndexDocumentsMethod::UpdateDocuments,
+                format: v2::updates::UpdateFormat::Json,
+                primary_key: None,
             }
+        }
+        v1::update::UpdateType::DocumentsDeletion { .. } => {
+            v2::updates::UpdateMeta::DeleteDocuments { ids: vec![] }
+        }
+        v1::update::UpdateType::Settings { settings } => v2::updates::UpdateMeta::Settings(
+            convert_settings_update(*settings, index_uid, Some(task_id), report),
+        ),
+    })
+}
+
+fn convert_settings(
+    source: v1::settings::Settings,
+    index_uid: &str,
+    report: &mut MigrationReport,
+) -> v2::Settings<v2::Unchecked> {
+    let displayed_attributes = source
+        .displayed_attributes
+        .map(|opt| opt.map(|displayed_attributes| displayed_attributes.into_iter().collect()));
+    let attributes_for_faceting = source.attributes_for_faceting.map(|opt| {
+        opt.map(|attributes_for_faceting| attributes_for_faceting.into_iter().collect())
+    });
+    let ranking_rules = source.ranking_rules.map(|opt| {
+        opt.map(|ranking_rules| {
+            ranking_rules
+                .into_iter()
+                .filter_map(|ranking_rule| {
+                    match v1::settings::RankingRule::from_str(&ranking_rule) {
+                        Ok(ranking_rule) => {
+                            convert_ranking_rule(ranking_rule, index_uid, None, report)
+                                .as_ref()
+                                .map(ToString::to_string)
+                        }
+                        Err(()) => Some(ranking_rule),
+                    }
+                })
+                .collect()
         })
+    });
+
+    v2::Settings {
+        displayed_attributes,
+        searchable_attributes: source.searchable_attributes,
+        filterable_attributes: attributes_for_faceting,
+        ranking_rules,
+        stop_words: source.stop_words,
+        synonyms: source.synonyms,
+        distinct_attribute: source.distinct_attribute,
+        _kind: std::marker::PhantomData,
     }
 }
 
-impl From<v1::settings::SettingsUpdate> for v2::Settings<v2::Unchecked> {
-    fn from(source: v1::settings::SettingsUpdate) -> Self {
-        let displayed_attributes: Option<Option<BTreeSet<String>>> =
-            source.displayed_attributes.into();
-
-        let attributes_for_faceting: Option<Option<Vec<String>>> =
-            source.attributes_for_faceting.into();
-
-        let ranking_rules: Option<Option<Vec<v1::settings::RankingRule>>> =
-            source.ranking_rules.into();
-
-        // go from the concrete types of v1 (RankingRule) to the concrete type of v2 (Criterion),
-        // and then back to string as this is what the settings manipulate
-        let ranking_rules = ranking_rules.map(|opt| {
-            opt.map(|ranking_rules| {
-                ranking_rules
-                    .into_iter()
-                    // filter out the WordsPosition ranking rule that exists in v1 but not v2
-                    .filter_map(|ranking_rule| {
-                        Option::<v2::settings::Criterion>::from(ranking_rule)
-                    })
-                    .map(|criterion| criterion.to_string())
-                    .collect()
-            })
-        });
+fn convert_settings_update(
+    source: v1::settings::SettingsUpdate,
+    index_uid: &str,
+    task_id: Option<u64>,
+    report: &mut MigrationReport,
+) -> v2::Settings<v2::Unchecked> {
+    let displayed_attributes: Option<Option<BTreeSet<String>>> =
+        source.displayed_attributes.into();
 
-        Self {
-            displayed_attributes: displayed_attributes.map(|opt| {
-                opt.map(|displayed_attributes| displayed_attributes.into_iter().collect())
-            }),
-            searchable_attributes: source.searchable_attributes.into(),
-            filterable_attributes: attributes_for_faceting.map(|opt| {
-                opt.map(|attributes_for_faceting| attributes_for_faceting.into_iter().collect())
-            }),
-            ranking_rules,
-            stop_words: source.stop_words.into(),
-            synonyms: source.synonyms.into(),
-            distinct_attribute: source.distinct_attribute.into(),
-            _kind: std::marker::PhantomData,
-        }
+    let attributes_for_faceting: Option<Option<Vec<String>>> =
+        source.attributes_for_faceting.into();
+
+    let ranking_rules: Option<Option<Vec<v1::settings::RankingRule>>> =
+        source.ranking_rules.into();
+
+    // go from the concrete types of v1 (RankingRule) to the concrete type of v2 (Criterion),
+    // and then back to string as this is what the settings manipulate
+    let ranking_rules = ranking_rules.map(|opt| {
+        opt.map(|ranking_rules| {
+            ranking_rules
+                .into_iter()
+                // filter out the WordsPosition ranking rule that exists in v1 but not v2
+                .filter_map(|ranking_rule| {
+                    convert_ranking_rule(ranking_rule, index_uid, task_id, report)
+                })
+                .map(|criterion| criterion.to_string())
+                .collect()
+        })
+    });
+
+    v2::Settings {
+        displayed_attributes: displayed_attributes
+            .map(|opt| opt.map(|displayed_attributes| displayed_attributes.into_iter().collect())),
+        searchable_attributes: source.searchable_attributes.into(),
+        filterable_attributes: attributes_for_faceting.map(|opt| {
+            opt.map(|attributes_for_faceting| attributes_for_faceting.into_iter().collect())
+        }),
+        ranking_rules,
+        stop_words: source.stop_words.into(),
+        synonyms: source.synonyms.into(),
+        distinct_attribute: source.distinct_attribute.into(),
+        _kind: std::marker::PhantomData,
     }
 }
 
-impl From<v1::settings::RankingRule> for Option<v2::settings::Criterion> {
-    fn from(source: v1::settings::RankingRule) -> Self {
-        match source {
-            v1::settings::RankingRule::Typo => Some(v2::settings::Criterion::Typo),
-            v1::settings::RankingRule::Words => Some(v2::settings::Criterion::Words),
-            v1::settings::RankingRule::Proximity => Some(v2::settings::Criterion::Proximity),
-            v1::settings::RankingRule::Attribute => Some(v2::settings::Criterion::Attribute),
-            v1::settings::RankingRule::WordsPosition => {
-                log::warn!("Removing the 'WordsPosition' ranking rule that is no longer supported, please check the resulting ranking rules of your indexes");
-                None
-            }
-            v1::settings::RankingRule::Exactness => Some(v2::settings::Criterion::Exactness),
-            v1::settings::RankingRule::Asc(field_name) => {
-                Some(v2::settings::Criterion::Asc(field_name))
-            }
-            v1::settings::RankingRule::Desc(field_name) => {
-                Some(v2::settings::Criterion::Desc(field_name))
-            }
+fn convert_ranking_rule(
+    source: v1::settings::RankingRule,
+    index_uid: &str,
+    task_id: Option<u64>,
+    report: &mut MigrationReport,
+) -> Option<v2::settings::Criterion> {
+    match source {
+        v1::settings::RankingRule::Typo => Some(v2::settings::Criterion::Typo),
+        v1::settings::RankingRule::Words => Some(v2::settings::Criterion::Words),
+        v1::settings::RankingRule::Proximity => Some(v2::settings::Criterion::Proximity),
+        v1::settings::RankingRule::Attribute => Some(v2::settings::Criterion::Attribute),
+        v1::settings::RankingRule::WordsPosition => {
+            report.push(MigrationWarning::RemovedRankingRule {
+                index_uid: index_uid.to_string(),
+                task_id,
+            });
+
+            None
+        }
+        v1::settings::RankingRule::Exactness => Some(v2::settings::Criterion::Exactness),
+        v1::settings::RankingRule::Asc(field_name) => {
+            Some(v2::settings::Criterion::Asc(field_name))
+        }
+        v1::settings::RankingRule::Desc(field_name) => {
+            Some(v2::settings::Criterion::Desc(field_name))
         }
     }
 }
@@ -323,3 +514,127 @@ impl<T> From<v1::settings::UpdateState<T>> for Option<Option<T>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_accumulates_warnings_across_conversions() {
+        // Mirrors the `Rc<RefCell<MigrationReport>>` plumbing shared between `tasks()`,
+        // `settings()` and `report()`: warnings pushed by separate, unrelated conversions
+        // accumulate in the same report, and all show up when it is read back afterwards.
+        let report = Rc::new(RefCell::new(MigrationReport::default()));
+
+        // What `tasks()` does when it hits a task with the removed `Customs` update type.
+        let meta = convert_update_type(
+            v1::update::UpdateType::Customs,
+            "my-index",
+            7,
+            &mut report.borrow_mut(),
+        );
+        assert!(meta.is_none());
+
+        // What `settings()` does when it hits the removed `WordsPosition` ranking rule.
+        let criterion = convert_ranking_rule(
+            v1::settings::RankingRule::WordsPosition,
+            "my-index",
+            None,
+            &mut report.borrow_mut(),
+        );
+        assert!(criterion.is_none());
+
+        let report = report.borrow();
+        assert!(matches!(
+            report.warnings(),
+            [
+                MigrationWarning::UnsupportedCustomsTask { task_id: 7, .. },
+                MigrationWarning::RemovedRankingRule { .. },
+            ]
+        ));
+    }
+
+    #[test]
+    fn rewritten_ranking_rules_only_counts_this_calls_warnings() {
+        let mut report = MigrationReport::default();
+
+        // Warnings already present from an earlier call, e.g. `tasks()`/`settings()` having
+        // already run against this same shared report.
+        convert_ranking_rule(
+            v1::settings::RankingRule::WordsPosition,
+            "my-index",
+            None,
+            &mut report,
+        );
+        let warnings_before = report.warnings().len();
+        assert_eq!(warnings_before, 1);
+
+        // This call's own contribution.
+        convert_ranking_rule(
+            v1::settings::RankingRule::WordsPosition,
+            "my-index",
+            None,
+            &mut report,
+        );
+
+        let rewritten_ranking_rules = report.warnings()[warnings_before..]
+            .iter()
+            .filter(|warning| matches!(warning, MigrationWarning::RemovedRankingRule { .. }))
+            .count();
+
+        assert_eq!(
+            rewritten_ranking_rules, 1,
+            "must not recount warnings pushed to the shared report before this call"
+        );
+    }
+
+    #[test]
+    fn enqueued_task_without_update_file_is_skipped_and_reported() {
+        let mut report = MigrationReport::default();
+
+        let status = v1::update::UpdateStatus::Enqueued {
+            content: v1::update::Enqueued {
+                update_id: 42,
+                update_type: v1::update::UpdateType::ClearAll,
+                enqueued_at: time::OffsetDateTime::UNIX_EPOCH,
+                // No update file reachable in the dump layout: must be skipped, not panic or
+                // silently succeed.
+                content: None,
+            },
+        };
+
+        let converted = convert_update_status(status, "my-index", &mut report);
+
+        assert!(converted.is_none());
+        assert!(matches!(
+            report.warnings(),
+            [MigrationWarning::SkippedEnqueuedTask { task_id: 42, .. }]
+        ));
+    }
+
+    #[test]
+    fn enqueued_task_with_update_file_is_recovered() {
+        let path = std::env::temp_dir()
+            .join(format!("meilisearch-v1-to-v2-test-{:?}.jsonl", std::thread::current().id()));
+        std::fs::write(&path, br#"{"id":1,"title":"recovered"}"#).unwrap();
+
+        let mut report = MigrationReport::default();
+
+        let status = v1::update::UpdateStatus::Enqueued {
+            content: v1::update::Enqueued {
+                update_id: 42,
+                update_type: v1::update::UpdateType::ClearAll,
+                enqueued_at: time::OffsetDateTime::UNIX_EPOCH,
+                content: Some(path.clone()),
+            },
+        };
+
+        let converted = convert_update_status(status, "my-index", &mut report);
+        std::fs::remove_file(&path).unwrap();
+
+        let (update, update_file) = converted.expect("a reachable update file must be recovered");
+        assert!(matches!(update, v2::updates::UpdateStatus::Enqueued(_)));
+        assert!(update_file.is_some());
+        assert!(report.warnings().is_empty(), "a recovered task must not be reported as skipped");
+    }
+}